@@ -0,0 +1,14 @@
+use sensehat_stick::JoyStick;
+
+fn main() {
+    // `next_event`/`IntoIterator` read the device's fd directly while it's
+    // still in blocking mode, so this works in a plain `fn main()` with no
+    // async executor or Tokio runtime.
+    let stick = JoyStick::open().unwrap();
+    for event in stick {
+        match event {
+            Ok(ev) => println!("{:?}", ev),
+            Err(e) => eprintln!("Error: {:?}", e),
+        }
+    }
+}
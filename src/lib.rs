@@ -10,25 +10,73 @@ use futures_core::{ready, Stream};
 use glob::glob;
 use num_enum::TryFromPrimitive;
 
+use std::convert::TryFrom;
 use std::io;
+use std::path::{Path, PathBuf};
 use std::task::Poll;
 use std::time::{Duration, UNIX_EPOCH};
 
 // Device name provided by the hardware. We match against it.
 const SENSE_HAT_EVDEV_NAME: &[u8; 31] = b"Raspberry Pi Sense HAT Joystick";
 
+// `EV_SYN` type code, and the `SYN_REPORT`/`SYN_DROPPED` codes within it.
+const EV_SYN: u16 = 0;
+const SYN_REPORT: u16 = 0;
+const SYN_DROPPED: u16 = 3;
+
+// `EV_KEY` type code, covering both key presses and the joystick's BTN_* codes.
+const EV_KEY: u16 = 1;
+
 /// Direction in which the JoyStick is moved.
 ///
-/// Internally, it matches the key-press events:
+/// Internally, it matches the key-press events emitted by the legacy rpisense
+/// keymap:
 ///
 /// * `Direction::Enter = 28`
 /// * `Direction::Up = 103`
 /// * `Direction::Down = 108`
 /// * `Direction::Left = 105`
 /// * `Direction::Up = 106`
+///
+/// The mainline `sensehat-joystick` driver instead emits `BTN_DPAD_*` /
+/// `BTN_SELECT` codes, which `Direction::try_from` also recognizes:
+///
+/// * `Direction::Enter = 314` (`BTN_SELECT`)
+/// * `Direction::Up = 544` (`BTN_DPAD_UP`)
+/// * `Direction::Down = 545` (`BTN_DPAD_DOWN`)
+/// * `Direction::Left = 546` (`BTN_DPAD_LEFT`)
+/// * `Direction::Right = 547` (`BTN_DPAD_RIGHT`)
 #[repr(usize)]
-#[derive(Debug, TryFromPrimitive)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Direction {
+    Enter,
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// A key/button code that doesn't correspond to any known `Direction` keymap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownDirectionCode(pub usize);
+
+impl TryFrom<usize> for Direction {
+    type Error = UnknownDirectionCode;
+
+    fn try_from(code: usize) -> Result<Self, Self::Error> {
+        if let Ok(direction) = MainlineDirection::try_from(code) {
+            return Ok(direction.into());
+        }
+        LegacyDirection::try_from(code)
+            .map(Direction::from)
+            .map_err(|_| UnknownDirectionCode(code))
+    }
+}
+
+/// Legacy rpisense `KEY_*` keymap.
+#[repr(usize)]
+#[derive(Debug, TryFromPrimitive)]
+enum LegacyDirection {
     Enter = 28,
     Up = 103,
     Down = 108,
@@ -36,6 +84,69 @@ pub enum Direction {
     Right = 106,
 }
 
+/// Mainline `sensehat-joystick` `BTN_DPAD_*` / `BTN_SELECT` keymap.
+#[repr(usize)]
+#[derive(Debug, TryFromPrimitive)]
+enum MainlineDirection {
+    Enter = 314,
+    Up = 544,
+    Down = 545,
+    Left = 546,
+    Right = 547,
+}
+
+impl From<LegacyDirection> for Direction {
+    fn from(direction: LegacyDirection) -> Self {
+        match direction {
+            LegacyDirection::Enter => Direction::Enter,
+            LegacyDirection::Up => Direction::Up,
+            LegacyDirection::Down => Direction::Down,
+            LegacyDirection::Left => Direction::Left,
+            LegacyDirection::Right => Direction::Right,
+        }
+    }
+}
+
+impl From<MainlineDirection> for Direction {
+    fn from(direction: MainlineDirection) -> Self {
+        match direction {
+            MainlineDirection::Enter => Direction::Enter,
+            MainlineDirection::Up => Direction::Up,
+            MainlineDirection::Down => Direction::Down,
+            MainlineDirection::Left => Direction::Left,
+            MainlineDirection::Right => Direction::Right,
+        }
+    }
+}
+
+#[cfg(test)]
+mod direction_tests {
+    use super::*;
+
+    #[test]
+    fn recognizes_legacy_keymap() {
+        assert_eq!(Direction::try_from(28).unwrap(), Direction::Enter);
+        assert_eq!(Direction::try_from(103).unwrap(), Direction::Up);
+        assert_eq!(Direction::try_from(108).unwrap(), Direction::Down);
+        assert_eq!(Direction::try_from(105).unwrap(), Direction::Left);
+        assert_eq!(Direction::try_from(106).unwrap(), Direction::Right);
+    }
+
+    #[test]
+    fn recognizes_mainline_keymap() {
+        assert_eq!(Direction::try_from(314).unwrap(), Direction::Enter);
+        assert_eq!(Direction::try_from(544).unwrap(), Direction::Up);
+        assert_eq!(Direction::try_from(545).unwrap(), Direction::Down);
+        assert_eq!(Direction::try_from(546).unwrap(), Direction::Left);
+        assert_eq!(Direction::try_from(547).unwrap(), Direction::Right);
+    }
+
+    #[test]
+    fn rejects_unknown_codes() {
+        assert_eq!(Direction::try_from(1).unwrap_err(), UnknownDirectionCode(1));
+    }
+}
+
 /// The action that was executed with the given `Direction`.
 #[repr(usize)]
 #[derive(Debug, TryFromPrimitive)]
@@ -64,11 +175,365 @@ impl JoyStickEvent {
     }
 }
 
+/// An error produced while reading or decoding a [`JoyStickEvent`].
+#[derive(Debug)]
+pub enum JoyStickError {
+    /// The kernel reported a key value that doesn't map to a known [`Action`].
+    UnknownAction(i32),
+    /// The event timestamp predates the UNIX epoch.
+    InvalidTimestamp,
+}
+
+impl std::fmt::Display for JoyStickError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JoyStickError::UnknownAction(value) => {
+                write!(f, "key value {} does not correspond to an Action", value)
+            }
+            JoyStickError::InvalidTimestamp => {
+                write!(f, "event timestamp predates the UNIX epoch")
+            }
+        }
+    }
+}
+
+impl std::error::Error for JoyStickError {}
+
+impl From<JoyStickError> for io::Error {
+    fn from(e: JoyStickError) -> Self {
+        io::Error::new(io::ErrorKind::InvalidData, e)
+    }
+}
+
+/// A bitset snapshot of which directions are currently held, one bit per
+/// [`Direction`] — the software equivalent of the upstream kernel driver's
+/// `prev_states` register.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct JoyStickState(u8);
+
+impl JoyStickState {
+    fn bit(direction: Direction) -> u8 {
+        1 << direction as u8
+    }
+
+    /// Is `direction` currently held (pressed or held)?
+    pub fn is_held(&self, direction: Direction) -> bool {
+        self.0 & Self::bit(direction) != 0
+    }
+
+    fn set(&mut self, direction: Direction, held: bool) {
+        if held {
+            self.0 |= Self::bit(direction);
+        } else {
+            self.0 &= !Self::bit(direction);
+        }
+    }
+
+    /// The directions whose held-state differs between `self` and `other`.
+    pub fn changed_since(&self, other: JoyStickState) -> JoyStickState {
+        JoyStickState(self.0 ^ other.0)
+    }
+}
+
+#[cfg(test)]
+mod state_tests {
+    use super::*;
+
+    #[test]
+    fn starts_with_nothing_held() {
+        let state = JoyStickState::default();
+        assert!(!state.is_held(Direction::Up));
+        assert!(!state.is_held(Direction::Enter));
+    }
+
+    #[test]
+    fn set_marks_a_single_direction_held_independently_of_others() {
+        let mut state = JoyStickState::default();
+        state.set(Direction::Up, true);
+        assert!(state.is_held(Direction::Up));
+        assert!(!state.is_held(Direction::Down));
+        assert!(!state.is_held(Direction::Enter));
+
+        state.set(Direction::Up, false);
+        assert!(!state.is_held(Direction::Up));
+    }
+
+    #[test]
+    fn changed_since_reports_only_directions_that_flipped() {
+        let mut before = JoyStickState::default();
+        before.set(Direction::Up, true);
+
+        let mut after = before;
+        after.set(Direction::Up, false);
+        after.set(Direction::Down, true);
+
+        let changed = after.changed_since(before);
+        assert!(changed.is_held(Direction::Up));
+        assert!(changed.is_held(Direction::Down));
+        assert!(!changed.is_held(Direction::Left));
+    }
+}
+
+const ALL_DIRECTIONS: [Direction; 5] = [
+    Direction::Enter,
+    Direction::Up,
+    Direction::Down,
+    Direction::Left,
+    Direction::Right,
+];
+
+impl Direction {
+    fn legacy_code(self) -> usize {
+        match self {
+            Direction::Enter => LegacyDirection::Enter as usize,
+            Direction::Up => LegacyDirection::Up as usize,
+            Direction::Down => LegacyDirection::Down as usize,
+            Direction::Left => LegacyDirection::Left as usize,
+            Direction::Right => LegacyDirection::Right as usize,
+        }
+    }
+
+    fn mainline_code(self) -> usize {
+        match self {
+            Direction::Enter => MainlineDirection::Enter as usize,
+            Direction::Up => MainlineDirection::Up as usize,
+            Direction::Down => MainlineDirection::Down as usize,
+            Direction::Left => MainlineDirection::Left as usize,
+            Direction::Right => MainlineDirection::Right as usize,
+        }
+    }
+}
+
+// Re-derive `JoyStickState` from the device's actual key state (`EVIOCGKEY`,
+// wrapped by evdev as `Device::get_key_state`). Used to resynchronize after a
+// `SYN_DROPPED`, since the events discarded during the drop may have carried
+// press/release transitions we never saw.
+//
+// Note: evdev's own `EventStream` (used by the `Stream` impl, via
+// `DeviceHandle::Streaming`) resyncs a `SYN_DROPPED` internally - it
+// synthesizes individual key events from the post-drop key state rather than
+// ever forwarding a literal `SYN_DROPPED` to `poll_event` callers - so this
+// backstop is unlikely to ever trigger on that path. It's still reachable via
+// `next_event`'s `DeviceHandle::Blocking` path, which reads raw events off the
+// fd directly rather than going through `EventStream`. Not verified against a
+// real/virtual device either way.
+fn query_state(device: &Device) -> io::Result<JoyStickState> {
+    let pressed = device.get_key_state()?;
+    let mut state = JoyStickState::default();
+    for direction in ALL_DIRECTIONS {
+        let held = pressed.contains(evdev::Key::new(direction.legacy_code() as u16))
+            || pressed.contains(evdev::Key::new(direction.mainline_code() as u16));
+        state.set(direction, held);
+    }
+    Ok(state)
+}
+
+// The device handle backing a `JoyStick`, in one of two mutually exclusive
+// modes. A freshly-opened device starts `Blocking`, with its fd left in the
+// blocking mode the kernel gives it by default, so `next_event`/`IntoIterator`
+// work in a plain `fn main()` with no executor. It only becomes `Streaming` -
+// which sets the fd non-blocking and wraps it in a `tokio::io::unix::AsyncFd`,
+// per `evdev::Device::into_event_stream` - the first time the `Stream` impl is
+// actually polled, since constructing that `AsyncFd` panics outside a running
+// Tokio runtime.
+enum DeviceHandle {
+    Blocking(Device),
+    Streaming(EventStream),
+    // Set while `DeviceHandle::streaming` swaps modes. It stays set if
+    // `into_event_stream` fails there, since that call consumes the `Device`:
+    // the device is then gone, and `streaming` returns an error from then on.
+    Transitioning,
+}
+
+impl DeviceHandle {
+    fn device_mut(&mut self) -> &mut Device {
+        match self {
+            DeviceHandle::Blocking(device) => device,
+            DeviceHandle::Streaming(stream) => stream.device_mut(),
+            DeviceHandle::Transitioning => panic!("device was lost when switching to streaming mode failed"),
+        }
+    }
+
+    // Move into `Streaming` mode if this is the first time the async `Stream`
+    // impl has been polled, then return the `EventStream` either way.
+    fn streaming(&mut self) -> io::Result<&mut EventStream> {
+        if let DeviceHandle::Blocking(_) = self {
+            let device = match std::mem::replace(self, DeviceHandle::Transitioning) {
+                DeviceHandle::Blocking(device) => device,
+                _ => unreachable!(),
+            };
+            *self = DeviceHandle::Streaming(device.into_event_stream()?);
+        }
+        match self {
+            DeviceHandle::Streaming(stream) => Ok(stream),
+            _ => Err(io::Error::new(
+                io::ErrorKind::Other,
+                "device was lost when switching to streaming mode failed",
+            )),
+        }
+    }
+}
+
 /// A type representing the Sense HAT joystick device.
-#[pin_project::pin_project]
+#[pin_project::pin_project(PinnedDrop)]
 pub struct JoyStick {
-    #[pin]
-    device: EventStream,
+    device: DeviceHandle,
+    // Set between a `SYN_DROPPED` and the following `SYN_REPORT`, while we
+    // discard the stale/partial events the kernel buffer overflow left behind.
+    resyncing: bool,
+    // Live pressed-set, updated as events are decoded.
+    state: JoyStickState,
+    // Snapshot of `state` as of the last call to `JoyStick::changed`.
+    last_queried_state: JoyStickState,
+    // Whether `grab` currently holds an exclusive lock on the device, so
+    // `Drop` knows whether it needs to release it.
+    grabbed: bool,
+}
+
+// The outcome of decoding a single raw `evdev` event.
+enum DecodedEvent {
+    // A completed joystick event.
+    Event(io::Result<JoyStickEvent>),
+    // The event that ended a `SYN_DROPPED` resync; `state` needs to be
+    // re-derived from the device's actual key state before it can be trusted.
+    Resynced,
+    // Not a completed joystick event (a SYN marker, a non-direction key, or
+    // one discarded while resyncing after a dropped buffer); keep reading.
+    Skip,
+}
+
+// Converts an event's raw `SystemTime` to a UNIX-epoch-relative `Duration`,
+// split out of `decode_event` so the pre-epoch rejection can be unit tested
+// without needing a real (or synthetic) `evdev::InputEvent`.
+fn event_timestamp(time: std::time::SystemTime) -> Result<Duration, JoyStickError> {
+    time.duration_since(UNIX_EPOCH)
+        .map_err(|_| JoyStickError::InvalidTimestamp)
+}
+
+// Decode a single raw `evdev` event, updating `resyncing` as `SYN_DROPPED`/
+// `SYN_REPORT` markers come through and `state` as directions are
+// pressed/held/released.
+fn decode_event(
+    key: evdev::InputEvent,
+    resyncing: &mut bool,
+    state: &mut JoyStickState,
+) -> DecodedEvent {
+    if key.event_type().0 == EV_SYN {
+        match key.code() {
+            SYN_DROPPED => *resyncing = true,
+            SYN_REPORT if *resyncing => {
+                *resyncing = false;
+                return DecodedEvent::Resynced;
+            }
+            _ => {}
+        }
+        return DecodedEvent::Skip;
+    }
+
+    if *resyncing || key.event_type().0 != EV_KEY {
+        return DecodedEvent::Skip;
+    }
+
+    let time = match event_timestamp(key.timestamp()) {
+        Ok(time) => time,
+        Err(e) => return DecodedEvent::Event(Err(e.into())),
+    };
+
+    let direction = match Direction::try_from(key.code() as usize) {
+        Ok(direction) => direction,
+        // Not every EV_KEY code is a joystick direction; ignore the rest.
+        Err(_) => return DecodedEvent::Skip,
+    };
+    let action = match Action::try_from(key.value() as usize) {
+        Ok(action) => action,
+        Err(_) => {
+            return DecodedEvent::Event(Err(JoyStickError::UnknownAction(key.value()).into()))
+        }
+    };
+
+    state.set(direction, !matches!(action, Action::Release));
+
+    DecodedEvent::Event(Ok(JoyStickEvent::new(time, direction, action)))
+}
+
+#[cfg(test)]
+mod decode_event_tests {
+    use super::*;
+
+    fn key_event(code: u16, value: i32) -> evdev::InputEvent {
+        evdev::InputEvent::new(evdev::EventType(EV_KEY), code, value)
+    }
+
+    fn syn_event(code: u16) -> evdev::InputEvent {
+        evdev::InputEvent::new(evdev::EventType(EV_SYN), code, 0)
+    }
+
+    #[test]
+    fn pre_epoch_timestamp_is_rejected() {
+        let before_epoch = UNIX_EPOCH - Duration::from_secs(1);
+        assert!(matches!(
+            event_timestamp(before_epoch),
+            Err(JoyStickError::InvalidTimestamp)
+        ));
+    }
+
+    #[test]
+    fn out_of_range_action_value_is_unknown_action() {
+        let mut resyncing = false;
+        let mut state = JoyStickState::default();
+        let key = key_event(Direction::Up.legacy_code() as u16, 9);
+        match decode_event(key, &mut resyncing, &mut state) {
+            DecodedEvent::Event(Err(e)) => {
+                let inner = e.into_inner().expect("JoyStickError should be boxed");
+                let err = inner
+                    .downcast::<JoyStickError>()
+                    .expect("error should be a JoyStickError");
+                assert!(matches!(*err, JoyStickError::UnknownAction(9)));
+            }
+            _ => panic!("expected an UnknownAction error"),
+        }
+    }
+
+    #[test]
+    fn non_direction_key_is_skipped() {
+        let mut resyncing = false;
+        let mut state = JoyStickState::default();
+        // KEY_ESC: a real EV_KEY code, but not one of our direction keymaps.
+        let key = key_event(1, Action::Press as i32);
+        assert!(matches!(
+            decode_event(key, &mut resyncing, &mut state),
+            DecodedEvent::Skip
+        ));
+    }
+
+    #[test]
+    fn syn_dropped_then_syn_report_resyncs() {
+        let mut resyncing = false;
+        let mut state = JoyStickState::default();
+
+        assert!(matches!(
+            decode_event(syn_event(SYN_DROPPED), &mut resyncing, &mut state),
+            DecodedEvent::Skip
+        ));
+        assert!(resyncing);
+
+        assert!(matches!(
+            decode_event(syn_event(SYN_REPORT), &mut resyncing, &mut state),
+            DecodedEvent::Resynced
+        ));
+        assert!(!resyncing);
+    }
+
+    #[test]
+    fn syn_report_without_a_drop_is_just_skipped() {
+        let mut resyncing = false;
+        let mut state = JoyStickState::default();
+        assert!(matches!(
+            decode_event(syn_event(SYN_REPORT), &mut resyncing, &mut state),
+            DecodedEvent::Skip
+        ));
+        assert!(!resyncing);
+    }
 }
 
 impl Stream for JoyStick {
@@ -77,27 +542,43 @@ impl Stream for JoyStick {
         self: std::pin::Pin<&mut Self>,
         cx: &mut std::task::Context<'_>,
     ) -> std::task::Poll<Option<Self::Item>> {
-        let mut this = self.project();
+        let this = self.project();
         loop {
-            let key = match ready!(this.device.poll_event(cx)) {
+            let stream = match this.device.streaming() {
+                Ok(stream) => stream,
+                Err(e) => return Poll::Ready(Some(Err(e))),
+            };
+            let key = match ready!(stream.poll_event(cx)) {
                 Ok(key) => key,
                 Err(e) => return Poll::Ready(Some(Err(e))),
             };
-
-            if key.event_type().0 != 1 {
-                continue;
+            match decode_event(key, this.resyncing, this.state) {
+                DecodedEvent::Event(result) => return Poll::Ready(Some(result)),
+                DecodedEvent::Resynced => match query_state(this.device.device_mut()) {
+                    Ok(state) => *this.state = state,
+                    Err(e) => return Poll::Ready(Some(Err(e))),
+                },
+                DecodedEvent::Skip => {}
             }
-
-            let time = key.timestamp().duration_since(UNIX_EPOCH).unwrap();
-
-            let direction = Direction::try_from(key.code() as usize).unwrap();
-            let action = Action::try_from(key.value() as usize).unwrap();
-            return Poll::Ready(Some(Ok(JoyStickEvent::new(time, direction, action))));
         }
     }
 }
 
 impl JoyStick {
+    fn from_device(device: Device) -> io::Result<Self> {
+        Ok(JoyStick {
+            device: DeviceHandle::Blocking(device),
+            resyncing: false,
+            state: JoyStickState::default(),
+            last_queried_state: JoyStickState::default(),
+            grabbed: false,
+        })
+    }
+
+    fn matches_name(device: &Device) -> bool {
+        device.name().unwrap_or_default().as_bytes() == SENSE_HAT_EVDEV_NAME
+    }
+
     /// Open the joystick device by name in the `/dev/input/event*` path on the filesystem.
     pub fn open() -> Result<Self, io::Error> {
         for entry in glob("/dev/input/event*")
@@ -106,10 +587,8 @@ impl JoyStick {
             match entry {
                 Ok(path) => {
                     let device = Device::open(&path)?;
-                    if device.name().unwrap_or_default().as_bytes() == SENSE_HAT_EVDEV_NAME {
-                        return Ok(JoyStick {
-                            device: device.into_event_stream()?,
-                        });
+                    if Self::matches_name(&device) {
+                        return Self::from_device(device);
                     }
                 }
                 Err(e) => return Err(e.into_error()),
@@ -120,4 +599,135 @@ impl JoyStick {
             "No Joystick found",
         ));
     }
+
+    /// Open a specific `evdev` device node directly, bypassing the name match
+    /// `open` performs. Useful when the matching node isn't reachable by the
+    /// `/dev/input/event*` glob, or to point at a virtual `uinput` device in tests.
+    pub fn open_path(path: impl AsRef<Path>) -> io::Result<Self> {
+        let device = Device::open(path.as_ref())?;
+        Self::from_device(device)
+    }
+
+    /// Enumerate every `/dev/input/event*` device whose name matches the Sense
+    /// HAT joystick, pairing each with the path it was opened from. Useful when
+    /// more than one matching device is present (e.g. stacked HATs).
+    pub fn enumerate() -> impl Iterator<Item = (PathBuf, JoyStick)> {
+        glob("/dev/input/event*")
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.ok())
+            .filter_map(|path| {
+                let device = Device::open(&path).ok()?;
+                if !Self::matches_name(&device) {
+                    return None;
+                }
+                let stick = Self::from_device(device).ok()?;
+                Some((path, stick))
+            })
+    }
+
+    /// Block on the underlying file descriptor and return the next joystick event.
+    ///
+    /// This is a synchronous alternative to the `Stream` impl for callers that
+    /// don't want to bring in an async executor just to read a button press -
+    /// it reads the device's fd directly while it's still in its default
+    /// blocking mode, rather than going through the `Stream` impl's
+    /// `tokio::io::unix::AsyncFd`, which would require a running Tokio runtime.
+    ///
+    /// Once the `Stream` impl has been polled even once, the underlying fd has
+    /// switched to non-blocking/`AsyncFd` mode for good, and this returns an
+    /// error instead of silently falling back to polling it; mix [`JoyStick`]'s
+    /// two APIs by choosing one per device, not by alternating between them.
+    pub fn next_event(&mut self) -> io::Result<JoyStickEvent> {
+        loop {
+            let device = match &mut self.device {
+                DeviceHandle::Blocking(device) => device,
+                DeviceHandle::Streaming(_) | DeviceHandle::Transitioning => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::Other,
+                        "next_event called after the Stream impl had already been polled",
+                    ))
+                }
+            };
+            // Collect into a `Vec` before matching on each event: `fetch_events`
+            // borrows `device` for the iterator's lifetime, and the
+            // `Resynced` arm below needs its own `&Device` to re-query state.
+            let events: Vec<_> = device.fetch_events()?.collect();
+            for key in events {
+                match decode_event(key, &mut self.resyncing, &mut self.state) {
+                    DecodedEvent::Event(result) => return result,
+                    DecodedEvent::Resynced => {
+                        self.state = query_state(device)?;
+                    }
+                    DecodedEvent::Skip => {}
+                }
+            }
+        }
+    }
+
+    /// The live set of currently-held directions, updated as events are processed.
+    pub fn state(&self) -> JoyStickState {
+        self.state
+    }
+
+    /// The directions whose held-state has changed since the last call to
+    /// `changed` (or since the `JoyStick` was opened, on the first call).
+    pub fn changed(&mut self) -> JoyStickState {
+        let changed = self.state.changed_since(self.last_queried_state);
+        self.last_queried_state = self.state;
+        changed
+    }
+
+    /// Exclusively grab the underlying device (`EVIOCGRAB`), so its events stop
+    /// being delivered to any other reader (e.g. the Linux console). The grab
+    /// is released automatically when the `JoyStick` is dropped, or explicitly
+    /// via [`JoyStick::ungrab`].
+    pub fn grab(&mut self) -> io::Result<()> {
+        self.device.device_mut().grab()?;
+        self.grabbed = true;
+        Ok(())
+    }
+
+    /// Release a grab previously taken with [`JoyStick::grab`].
+    pub fn ungrab(&mut self) -> io::Result<()> {
+        self.device.device_mut().ungrab()?;
+        self.grabbed = false;
+        Ok(())
+    }
+}
+
+#[pin_project::pinned_drop]
+impl PinnedDrop for JoyStick {
+    fn drop(self: std::pin::Pin<&mut Self>) {
+        let this = self.project();
+        if *this.grabbed {
+            // `device` is a plain field (nothing about `JoyStick` needs
+            // pinning now that it no longer has an `EventStream` field until
+            // `streaming()` is called), so no `Pin` juggling is needed here.
+            let _ = this.device.device_mut().ungrab();
+        }
+    }
+}
+
+/// A blocking iterator over `JoyStick` events, built on [`JoyStick::next_event`].
+///
+/// Obtained via `JoyStick`'s [`IntoIterator`] impl.
+pub struct JoyStickIter {
+    stick: JoyStick,
+}
+
+impl Iterator for JoyStickIter {
+    type Item = io::Result<JoyStickEvent>;
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.stick.next_event())
+    }
+}
+
+impl IntoIterator for JoyStick {
+    type Item = io::Result<JoyStickEvent>;
+    type IntoIter = JoyStickIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        JoyStickIter { stick: self }
+    }
 }